@@ -21,16 +21,13 @@ impl BusDevice for BootTimer {
         }
 
         if data[0] == MAGIC_VALUE_SIGNAL_GUEST_BOOT_COMPLETE {
-            let now_tm_us = TimestampUs::default();
-
-            let boot_time_us = now_tm_us.time_us - self.start_ts.time_us;
-            let boot_time_cpu_us = now_tm_us.cputime_us - self.start_ts.cputime_us;
+            let boot_time = self.start_ts.since();
             info!(
                 "Guest-boot-time = {:>6} us {} ms, {:>6} CPU us {} CPU ms",
-                boot_time_us,
-                boot_time_us / 1000,
-                boot_time_cpu_us,
-                boot_time_cpu_us / 1000
+                boot_time.real_us,
+                boot_time.real_us / 1000,
+                boot_time.cpu_us,
+                boot_time.cpu_us / 1000
             );
         }
     }
@@ -122,6 +122,32 @@ impl Default for TimestampUs {
     }
 }
 
+impl TimestampUs {
+    /// Returns the real and cpu time elapsed since `self` was recorded.
+    pub fn since(&self) -> Elapsed {
+        self.elapsed_until(&TimestampUs::default())
+    }
+
+    /// Returns the real and cpu time elapsed between `self` and `later`.
+    ///
+    /// Saturates to zero if `later` precedes `self`.
+    pub fn elapsed_until(&self, later: &TimestampUs) -> Elapsed {
+        Elapsed {
+            real_us: later.time_us.saturating_sub(self.time_us),
+            cpu_us: later.cputime_us.saturating_sub(self.cputime_us),
+        }
+    }
+}
+
+/// Holds the micro-second resolution real time and cpu time elapsed between two timestamps.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Elapsed {
+    /// Elapsed real time in microseconds.
+    pub real_us: u64,
+    /// Elapsed cpu time in microseconds.
+    pub cpu_us: u64,
+}
+
 /// Returns a timestamp in nanoseconds from a monotonic clock.
 ///
 /// Uses `_rdstc` on `x86_64` and [`get_time`](fn.get_time.html) on other architectures.
@@ -241,6 +267,36 @@ mod tests {
         assert!(local_time.mon >= 0 && local_time.mon <= 11);
     }
 
+    #[test]
+    fn test_timestamp_elapsed() {
+        let start = TimestampUs {
+            time_us: 1_000,
+            cputime_us: 200,
+        };
+        let end = TimestampUs {
+            time_us: 3_500,
+            cputime_us: 450,
+        };
+        assert_eq!(
+            start.elapsed_until(&end),
+            Elapsed {
+                real_us: 2_500,
+                cpu_us: 250
+            }
+        );
+        // A later sample preceding the baseline saturates instead of underflowing.
+        assert_eq!(end.elapsed_until(&start), Elapsed::default());
+
+        let baseline = TimestampUs {
+            time_us: 0,
+            cputime_us: 0,
+        };
+        let now = TimestampUs::default();
+        let elapsed = baseline.since();
+        assert!(elapsed.real_us >= now.time_us);
+        assert!(elapsed.cpu_us >= now.cputime_us);
+    }
+
     #[test]
     fn test_seconds_to_nanoseconds() {
         assert_eq!(